@@ -0,0 +1,205 @@
+use std::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::intoiter::RawValIter;
+use crate::Vector;
+
+use crate::alloc::{Allocator, Global};
+
+/// A draining iterator for a sub-range of a [`Vector`], created by [`Vector::drain`].
+///
+/// When dropped, any remaining elements are dropped and the tail of the
+/// vector is shifted down to close the gap left by the drained range, even
+/// if the `Drain` was only partially consumed. If the `Drain` is leaked
+/// (e.g. via `mem::forget`), no shifting happens: the vector keeps the
+/// length it was truncated to when the `Drain` was created, and the drained
+/// and tail elements are leaked.
+pub struct Drain<'a, T: 'a, A: Allocator = Global> {
+    // Index just past the drained range; where the tail starts in the
+    // original vector.
+    tail_start: usize,
+    // Number of elements in the tail that need to be shifted back down.
+    tail_len: usize,
+    iter: RawValIter<T>,
+    vector: NonNull<Vector<T, A>>,
+    _marker: PhantomData<&'a mut Vector<T, A>>,
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    /// Removes the specified range from the vector, returning an iterator
+    /// over the removed elements.
+    ///
+    /// The vector's length is set to the start of `range` as soon as the
+    /// `Drain` is created, so leaking the `Drain` (e.g. via `mem::forget`)
+    /// simply leaves the tail elements un-dropped rather than causing the
+    /// vector to observe freed or aliased elements.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+
+        assert!(start <= end, "drain start is after drain end");
+        assert!(end <= len, "drain end is out of bounds");
+
+        unsafe {
+            self.set_len(start);
+
+            let range_slice = slice::from_raw_parts(self.as_ptr().add(start), end - start);
+
+            Drain {
+                tail_start: end,
+                tail_len: len - end,
+                iter: RawValIter::new(range_slice),
+                vector: NonNull::from(self),
+                _marker: PhantomData,
+            }
+        }
+    }
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        // Drop any elements that weren't consumed.
+        for _ in &mut *self {}
+
+        if self.tail_len > 0 {
+            unsafe {
+                let vector = self.vector.as_mut();
+                let start = vector.len();
+
+                if self.tail_start != start {
+                    let src = vector.as_ptr().add(self.tail_start);
+                    let dst = vector.as_mut_ptr().add(start);
+                    ptr::copy(src, dst, self.tail_len);
+                }
+
+                vector.set_len(start + self.tail_len);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn drain_removes_range_and_shifts_tail() {
+        let mut vector = crate::Vector::new();
+
+        for i in 0..10 {
+            vector.push(i);
+        }
+
+        let drained = vector.drain(2..5).collect::<Vec<_>>();
+        assert_eq!(drained, vec![2, 3, 4]);
+        assert_eq!(vector.len(), 7);
+
+        let remaining = (0..vector.len())
+            .map(|i| *vector.get(i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, vec![0, 1, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn drain_drops_everything_on_full_consumption() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct Dropper;
+
+        impl Drop for Dropper {
+            fn drop(&mut self) {
+                COUNTER.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut vector = crate::Vector::new();
+
+        for _ in 0..10 {
+            vector.push(Dropper);
+        }
+
+        for _ in vector.drain(2..8) {}
+
+        assert_eq!(COUNTER.load(Ordering::Relaxed), 6);
+        assert_eq!(vector.len(), 4);
+    }
+
+    #[test]
+    fn drain_drops_remaining_on_partial_consumption() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct Dropper;
+
+        impl Drop for Dropper {
+            fn drop(&mut self) {
+                COUNTER.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut vector = crate::Vector::new();
+
+        for _ in 0..10 {
+            vector.push(Dropper);
+        }
+
+        {
+            let mut drain = vector.drain(2..8);
+            drain.next();
+            drain.next();
+            // the remaining 4 drained elements drop here, when `drain` goes
+            // out of scope without being fully consumed
+        }
+
+        assert_eq!(COUNTER.load(Ordering::Relaxed), 6);
+        assert_eq!(vector.len(), 4);
+    }
+
+    #[test]
+    fn leaking_drain_leaves_the_vector_memory_safe() {
+        let mut vector = crate::Vector::new();
+
+        for i in 0..10 {
+            vector.push(i);
+        }
+
+        let drain = vector.drain(3..7);
+        std::mem::forget(drain);
+
+        // The vector's length was truncated to the drain start up front, so
+        // leaking the `Drain` just leaks the drained (and un-shifted tail)
+        // elements instead of leaving the vector pointing at freed or
+        // aliased memory.
+        assert_eq!(vector.len(), 3);
+    }
+}
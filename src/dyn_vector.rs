@@ -0,0 +1,366 @@
+use std::{
+    alloc::Layout,
+    marker::{PhantomData, Unsize},
+    ptr::{self, NonNull, Pointee},
+};
+
+use crate::RawVector;
+
+use crate::alloc::{Allocator, Global};
+
+/// One slot in a [`DynVector`]'s metadata table: where the element's bytes
+/// start within the byte buffer, plus the pointer metadata needed to
+/// reconstruct a `&dyn Trait` (or other unsized) reference to it.
+struct Entry<Dyn: ?Sized + Pointee> {
+    offset: usize,
+    metadata: <Dyn as Pointee>::Metadata,
+}
+
+impl<Dyn: ?Sized + Pointee> Clone for Entry<Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Dyn: ?Sized + Pointee> Copy for Entry<Dyn> {}
+
+/// The byte region backing a [`DynVector`].
+///
+/// Unlike `RawVector<u8>`, whose allocation is only ever guaranteed
+/// `align_of::<u8>() == 1`, this tracks the alignment the buffer was
+/// actually allocated with so that elements with `align_of::<U>() > 1` can
+/// be placed in it soundly. `align` only ever grows: when a pushed element
+/// needs a stricter alignment than the buffer currently has, the whole
+/// region is reallocated at the new (larger) alignment. Because alignments
+/// are powers of two, a larger alignment is always a multiple of every
+/// smaller one the buffer previously had, so already-recorded offsets
+/// (each a multiple of its own element's alignment) stay correctly aligned
+/// against the new base without being recomputed.
+struct ByteArena {
+    ptr: NonNull<u8>,
+    cap: usize,
+    align: usize,
+}
+
+impl ByteArena {
+    fn dangling() -> Self {
+        ByteArena {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            align: 1,
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        Layout::from_size_align(self.cap, self.align).unwrap()
+    }
+}
+
+/// A vector of unsized values (trait objects, slices, ...) stored
+/// contiguously.
+///
+/// Element bytes are bump-allocated into a single growable, correctly
+/// over-aligned [`ByteArena`], while a parallel [`RawVector<Entry<Dyn>>`]
+/// records each element's byte offset and [`core::ptr::Pointee`] metadata
+/// so it can be reconstructed with [`ptr::from_raw_parts`].
+pub struct DynVector<Dyn: ?Sized + Pointee, A: Allocator = Global> {
+    bytes: ByteArena,
+    byte_len: usize,
+    entries: RawVector<Entry<Dyn>>,
+    len: usize,
+    allocator: A,
+    _marker: PhantomData<Dyn>,
+}
+
+impl<Dyn: ?Sized + Pointee> DynVector<Dyn, Global> {
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<Dyn: ?Sized + Pointee, A: Allocator> DynVector<Dyn, A> {
+    pub fn new_in(allocator: A) -> Self {
+        DynVector {
+            bytes: ByteArena::dangling(),
+            byte_len: 0,
+            entries: RawVector::new(),
+            len: 0,
+            allocator,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Pushes `value` onto the end of the vector, erasing it to `Dyn`.
+    ///
+    /// `value`'s bytes are copied into the byte buffer and its pointer
+    /// metadata and offset are recorded in the entry table. `U` is the
+    /// concrete type (inferred, e.g. `vector.push(concrete)`); it is unsized
+    /// to the vector's `Dyn` so `ptr::metadata` can capture the right
+    /// vtable/length.
+    pub fn push<U>(&mut self, value: U)
+    where
+        U: Unsize<Dyn>,
+    {
+        let layout = Layout::new::<U>();
+        let offset = self.reserve_bytes(layout);
+
+        unsafe {
+            let dst = self.bytes.ptr.as_ptr().add(offset) as *mut U;
+            ptr::write(dst, value);
+
+            let fat: *const Dyn = dst as *const U;
+            let metadata = ptr::metadata(fat);
+
+            self.reserve_entry();
+            let entry_dst = self.entries.ptr().as_ptr().add(self.len);
+            entry_dst.write(Entry { offset, metadata });
+        }
+
+        self.byte_len = offset + layout.size();
+        self.len += 1;
+    }
+
+    /// Returns a reference to the `i`th element, reconstructed from its
+    /// recorded offset and pointer metadata.
+    pub fn get(&self, i: usize) -> Option<&Dyn> {
+        if i >= self.len {
+            return None;
+        }
+
+        unsafe {
+            let entry = *self.entries.ptr().as_ptr().add(i);
+            let data = self.bytes.ptr.as_ptr().add(entry.offset) as *const ();
+            Some(&*ptr::from_raw_parts(data, entry.metadata))
+        }
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut Dyn> {
+        if i >= self.len {
+            return None;
+        }
+
+        unsafe {
+            let entry = *self.entries.ptr().as_ptr().add(i);
+            let data = self.bytes.ptr.as_ptr().add(entry.offset) as *mut ();
+            Some(&mut *ptr::from_raw_parts_mut(data, entry.metadata))
+        }
+    }
+
+    /// Ensures the byte arena has room for `layout` at a correctly aligned
+    /// offset, growing (and, if `layout.align()` exceeds the arena's current
+    /// alignment, reallocating at the larger alignment) as needed. Returns
+    /// the offset the element should be written at.
+    fn reserve_bytes(&mut self, layout: Layout) -> usize {
+        let offset = align_offset(self.byte_len, layout.align());
+        let needed = offset + layout.size();
+        let needed_align = self.bytes.align.max(layout.align());
+
+        if needed > self.bytes.cap || needed_align > self.bytes.align {
+            let mut new_cap = if self.bytes.cap == 0 {
+                4
+            } else {
+                self.bytes.cap * 2
+            };
+            new_cap = new_cap.max(needed);
+
+            let new_layout = Layout::from_size_align(new_cap, needed_align)
+                .expect("byte arena layout overflow");
+            let new_ptr = self
+                .allocator
+                .allocate(new_layout)
+                .expect("dyn vector allocation failed")
+                .as_ptr() as *mut u8;
+
+            if self.byte_len > 0 {
+                unsafe {
+                    ptr::copy_nonoverlapping(self.bytes.ptr.as_ptr(), new_ptr, self.byte_len);
+                }
+            }
+
+            if self.bytes.cap > 0 {
+                unsafe {
+                    self.allocator.deallocate(self.bytes.ptr, self.bytes.layout());
+                }
+            }
+
+            self.bytes = ByteArena {
+                ptr: NonNull::new(new_ptr).unwrap(),
+                cap: new_cap,
+                align: needed_align,
+            };
+        }
+
+        offset
+    }
+
+    fn reserve_entry(&mut self) {
+        if self.len == self.entries.cap() {
+            unsafe { self.entries.grow(&self.allocator) };
+        }
+    }
+}
+
+/// Rounds `offset` up to the next multiple of `align`, so heterogeneous
+/// element types packed into the same byte region each start correctly
+/// aligned.
+fn align_offset(offset: usize, align: usize) -> usize {
+    (offset + align - 1) & !(align - 1)
+}
+
+impl<Dyn: ?Sized + Pointee, A: Allocator> Drop for DynVector<Dyn, A> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.len {
+                let entry = *self.entries.ptr().as_ptr().add(i);
+                let data = self.bytes.ptr.as_ptr().add(entry.offset) as *mut ();
+                let fat: *mut Dyn = ptr::from_raw_parts_mut(data, entry.metadata);
+                ptr::drop_in_place(fat);
+            }
+
+            if self.bytes.cap > 0 {
+                self.allocator.deallocate(self.bytes.ptr, self.bytes.layout());
+            }
+            self.entries.deallocate_no_drop(&self.allocator);
+        }
+    }
+}
+
+pub struct DynIntoIter<Dyn: ?Sized + Pointee, A: Allocator = Global> {
+    vector: DynVector<Dyn, A>,
+    next: usize,
+}
+
+impl<Dyn: ?Sized + Pointee, A: Allocator> IntoIterator for DynVector<Dyn, A> {
+    type Item = NonNull<Dyn>;
+    type IntoIter = DynIntoIter<Dyn, A>;
+
+    fn into_iter(self) -> DynIntoIter<Dyn, A> {
+        DynIntoIter {
+            vector: self,
+            next: 0,
+        }
+    }
+}
+
+impl<Dyn: ?Sized + Pointee, A: Allocator> Iterator for DynIntoIter<Dyn, A> {
+    // The returned pointer points into the `DynVector`'s bump arena, not a
+    // standalone allocation — it must never be passed to a deallocator
+    // (e.g. via `Box::from_raw`), which would hand arena-interior memory to
+    // the global allocator. Once yielded, the `DynIntoIter`'s own `Drop`
+    // (which only reaps indices in `self.next..len`) will no longer drop
+    // it, so the caller owns exactly one `ptr::drop_in_place` on it; if the
+    // caller does nothing, the element's destructor simply never runs
+    // (a leak, not a double free/UB) when the arena is eventually freed.
+    type Item = NonNull<Dyn>;
+
+    fn next(&mut self) -> Option<NonNull<Dyn>> {
+        if self.next >= self.vector.len {
+            return None;
+        }
+
+        let fat = self.vector.get_mut(self.next)? as *mut Dyn;
+        self.next += 1;
+        Some(unsafe { NonNull::new_unchecked(fat) })
+    }
+}
+
+impl<Dyn: ?Sized + Pointee, A: Allocator> Drop for DynIntoIter<Dyn, A> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in self.next..self.vector.len {
+                let entry = *self.vector.entries.ptr().as_ptr().add(i);
+                let data = self.vector.bytes.ptr.as_ptr().add(entry.offset) as *mut ();
+                let fat: *mut Dyn = ptr::from_raw_parts_mut(data, entry.metadata);
+                ptr::drop_in_place(fat);
+            }
+            // Elements have already been dropped (or moved out by the
+            // caller), so mark the vector empty to stop its own `Drop`
+            // from double-dropping them; it will still free the backing
+            // buffers.
+            self.vector.len = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    trait Value {
+        fn value(&self) -> u64;
+    }
+
+    #[repr(align(1))]
+    struct Small(u8);
+
+    impl Value for Small {
+        fn value(&self) -> u64 {
+            self.0 as u64
+        }
+    }
+
+    #[repr(align(16))]
+    struct Big(u64, u64);
+
+    impl Value for Big {
+        fn value(&self) -> u64 {
+            self.0 + self.1
+        }
+    }
+
+    #[test]
+    fn push_and_get_heterogeneously_aligned_elements() {
+        let mut vector: DynVector<dyn Value> = DynVector::new();
+
+        vector.push(Small(3));
+        vector.push(Big(10, 20));
+        vector.push(Small(7));
+
+        assert_eq!(vector.len(), 3);
+        assert_eq!(vector.get(0).unwrap().value(), 3);
+        assert_eq!(vector.get(1).unwrap().value(), 30);
+        assert_eq!(vector.get(2).unwrap().value(), 7);
+
+        // The `Big` element must land on an address aligned to its own
+        // (stricter) alignment, not just the arena's original alignment.
+        let big_ptr = vector.get(1).unwrap() as *const dyn Value as *const () as usize;
+        assert_eq!(big_ptr % std::mem::align_of::<Big>(), 0);
+    }
+
+    #[test]
+    fn drop_runs_exactly_once_per_element() {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct Dropper;
+
+        impl Value for Dropper {
+            fn value(&self) -> u64 {
+                0
+            }
+        }
+
+        impl Drop for Dropper {
+            fn drop(&mut self) {
+                COUNTER.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        {
+            let mut vector: DynVector<dyn Value> = DynVector::new();
+            for _ in 0..5 {
+                vector.push(Dropper);
+            }
+        }
+
+        assert_eq!(COUNTER.load(Ordering::Relaxed), 5);
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
-    mem,
+    mem::{self, ManuallyDrop},
     ptr::{self, NonNull},
+    slice,
 };
 
 use crate::{RawVector, Vector};
@@ -10,7 +11,22 @@ use crate::alloc::{Allocator, Global};
 pub struct IntoIter<T, A: Allocator = Global> {
     _buf: RawVector<T>, // we don't actually care about this. Just need it to live.
     iter: RawValIter<T>,
-    pub(crate) allocator: A,
+    // Wrapped so that `Drop` can move the allocator out to deallocate the
+    // buffer without also letting normal struct-field drop glue run on it
+    // afterwards (mirrors `std::vec::IntoIter`'s `alloc: ManuallyDrop<A>`).
+    pub(crate) allocator: ManuallyDrop<A>,
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    /// Returns the remaining, not-yet-yielded elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+
+    /// Returns the remaining, not-yet-yielded elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.iter.as_mut_slice()
+    }
 }
 
 impl<T, A: Allocator> Iterator for IntoIter<T, A> {
@@ -23,13 +39,13 @@ impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
     fn next_back(&mut self) -> Option<T> {
         self.iter.next_back()
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
 
 impl<T, A: Allocator> Drop for IntoIter<T, A> {
     fn drop(&mut self) {
@@ -37,34 +53,44 @@ impl<T, A: Allocator> Drop for IntoIter<T, A> {
         for _ in &mut *self {}
 
         unsafe {
-            self._buf.deallocate_no_drop(&self.allocator);
+            // Move the allocator out so we can hand it to `deallocate_no_drop`
+            // by value; the `ManuallyDrop` wrapper stops it from also being
+            // dropped normally once this function returns.
+            let allocator = ManuallyDrop::take(&mut self.allocator);
+            self._buf.deallocate_no_drop(allocator);
         }
     }
 }
 
 impl<T, A: Allocator> IntoIterator for Vector<T, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
-    fn into_iter(self) -> IntoIter<T> {
-        let (iter, buf) = unsafe { (RawValIter::new(&self), ptr::read(&self.raw)) };
+    type IntoIter = IntoIter<T, A>;
+    fn into_iter(self) -> IntoIter<T, A> {
+        let (iter, buf, allocator) = unsafe {
+            (
+                RawValIter::new(&self),
+                ptr::read(&self.raw),
+                ptr::read(&self.allocator),
+            )
+        };
 
         mem::forget(self);
 
         IntoIter {
             iter,
             _buf: buf,
-            allocator: Global,
+            allocator: ManuallyDrop::new(allocator),
         }
     }
 }
 
-struct RawValIter<T> {
+pub(crate) struct RawValIter<T> {
     start: *const T,
     end: *const T,
 }
 
 impl<T> RawValIter<T> {
-    unsafe fn new(slice: &[T]) -> Self {
+    pub(crate) unsafe fn new(slice: &[T]) -> Self {
         RawValIter {
             start: slice.as_ptr(),
             end: if mem::size_of::<T>() == 0 {
@@ -78,6 +104,22 @@ impl<T> RawValIter<T> {
     }
 }
 
+impl<T> RawValIter<T> {
+    /// Number of elements left between `start` and `end`.
+    fn len(&self) -> usize {
+        let elem_size = mem::size_of::<T>();
+        (self.end as usize - self.start as usize) / if elem_size == 0 { 1 } else { elem_size }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.start, self.len()) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.start as *mut T, self.len()) }
+    }
+}
+
 impl<T> Iterator for RawValIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<T> {
@@ -98,9 +140,7 @@ impl<T> Iterator for RawValIter<T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let elem_size = mem::size_of::<T>();
-        let len =
-            (self.end as usize - self.start as usize) / if elem_size == 0 { 1 } else { elem_size };
+        let len = self.len();
         (len, Some(len))
     }
 }
@@ -0,0 +1,230 @@
+use std::{
+    alloc::{self, Layout},
+    mem,
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::alloc::{AllocError, Allocator};
+
+/// Bits reserved for the ABA-prevention tag packed into the free-list head.
+/// The remaining bits hold the slot index, with `N` itself used as the
+/// "stack is empty" sentinel.
+const TAG_BITS: u32 = 16;
+
+/// A fixed-capacity, lock-free pool allocator.
+///
+/// `Pool<N>` carves `N` same-[`Layout`] slots out of a single pre-allocated
+/// block and serves them through a Treiber-stack free list, so a `Vector`
+/// can be allocated from a bounded arena with no global-heap traffic and no
+/// locking. `allocate` returns [`AllocError`] once the pool is exhausted
+/// rather than falling back to the global allocator.
+pub struct Pool<const N: usize> {
+    storage: NonNull<u8>,
+    // The layout callers allocate/deallocate against. `allocate`/`deallocate`
+    // assert incoming layouts match this, not `stride`.
+    slot_layout: Layout,
+    // Distance between consecutive slots. At least `slot_layout.size()`, but
+    // bumped up to fit a `usize` free-list pointer when the slot is smaller
+    // than that and rounded to a multiple of `usize`'s alignment (the
+    // storage is aligned to at least that too), so the free list can always
+    // be threaded through the slots themselves.
+    stride: usize,
+    // Packed as `(tag << INDEX_BITS) | index`, where `index == N` means the
+    // free list is empty. `tag` is bumped on every push/pop so a CAS can't
+    // succeed after a slot was popped and pushed back with the same index
+    // (the ABA problem).
+    head: AtomicUsize,
+}
+
+unsafe impl<const N: usize> Send for Pool<N> {}
+unsafe impl<const N: usize> Sync for Pool<N> {}
+
+impl<const N: usize> Pool<N> {
+    const EMPTY: usize = N;
+    const INDEX_BITS: u32 = usize::BITS - TAG_BITS;
+    const INDEX_MASK: usize = (1 << Self::INDEX_BITS) - 1;
+
+    /// Allocates the pool's backing storage and threads the free list
+    /// through every slot. Every allocation served by this pool must use
+    /// `slot_layout` (padded to its alignment).
+    pub fn new(slot_layout: Layout) -> Self {
+        assert!(N > 0, "Pool must have a non-zero capacity");
+        assert!(
+            N <= Self::INDEX_MASK,
+            "Pool capacity exceeds the index range"
+        );
+
+        let slot_layout = slot_layout.pad_to_align();
+        let stride = slot_layout
+            .size()
+            .max(mem::size_of::<usize>())
+            .next_multiple_of(mem::align_of::<usize>());
+        let storage_align = slot_layout.align().max(mem::align_of::<usize>());
+        let array_layout = Layout::from_size_align(stride * N, storage_align)
+            .expect("pool storage layout overflow");
+
+        let storage = unsafe { alloc::alloc(array_layout) };
+        let storage =
+            NonNull::new(storage).unwrap_or_else(|| alloc::handle_alloc_error(array_layout));
+
+        // Slot `i` points at slot `i + 1`; the last slot points at `EMPTY`.
+        for i in 0..N {
+            unsafe {
+                let slot = storage.as_ptr().add(i * stride) as *const AtomicUsize;
+                let next = if i + 1 == N { Self::EMPTY } else { i + 1 };
+                (*slot).store(next, Ordering::Relaxed);
+            }
+        }
+
+        Pool {
+            storage,
+            slot_layout,
+            stride,
+            head: AtomicUsize::new(Self::pack(0, 0)),
+        }
+    }
+
+    fn pack(tag: usize, index: usize) -> usize {
+        (tag << Self::INDEX_BITS) | index
+    }
+
+    fn unpack(word: usize) -> (usize, usize) {
+        (word >> Self::INDEX_BITS, word & Self::INDEX_MASK)
+    }
+
+    unsafe fn slot_ptr(&self, index: usize) -> *mut u8 {
+        self.storage.as_ptr().add(index * self.stride)
+    }
+
+    /// The free-list "next" word threaded through a free slot, as an atomic
+    /// so concurrent `allocate`/`deallocate` calls never plainly read/write
+    /// the same word outside of the CAS loop that guards it.
+    unsafe fn next_link(&self, index: usize) -> &AtomicUsize {
+        &*(self.slot_ptr(index) as *const AtomicUsize)
+    }
+}
+
+unsafe impl<const N: usize> Allocator for Pool<N> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let layout = layout.pad_to_align();
+        assert_eq!(
+            layout.size(),
+            self.slot_layout.size(),
+            "Pool can only serve allocations matching its configured slot layout"
+        );
+        assert!(
+            layout.align() <= self.slot_layout.align(),
+            "Pool slots are only aligned to {}, which is less than the requested alignment",
+            self.slot_layout.align()
+        );
+
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            let (tag, index) = Self::unpack(head);
+
+            if index == Self::EMPTY {
+                return Err(AllocError);
+            }
+
+            let next = unsafe { self.next_link(index).load(Ordering::Relaxed) };
+            let new_head = Self::pack(tag.wrapping_add(1), next);
+
+            if self
+                .head
+                .compare_exchange_weak(head, new_head, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                let ptr = unsafe { NonNull::new_unchecked(self.slot_ptr(index)) };
+                return Ok(NonNull::slice_from_raw_parts(ptr, self.slot_layout.size()));
+            }
+        }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        let layout = layout.pad_to_align();
+        assert_eq!(
+            layout.size(),
+            self.slot_layout.size(),
+            "deallocated layout does not match this pool's slot layout"
+        );
+        assert!(
+            layout.align() <= self.slot_layout.align(),
+            "deallocated layout does not match this pool's slot layout"
+        );
+
+        let index = (ptr.as_ptr() as usize - self.storage.as_ptr() as usize) / self.stride;
+
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            let (tag, _) = Self::unpack(head);
+
+            self.next_link(index)
+                .store(head & Self::INDEX_MASK, Ordering::Relaxed);
+            let new_head = Self::pack(tag.wrapping_add(1), index);
+
+            if self
+                .head
+                .compare_exchange_weak(head, new_head, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl<const N: usize> Drop for Pool<N> {
+    fn drop(&mut self) {
+        let storage_align = self.slot_layout.align().max(mem::align_of::<usize>());
+        let array_layout = Layout::from_size_align(self.stride * N, storage_align).unwrap();
+        unsafe { alloc::dealloc(self.storage.as_ptr(), array_layout) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_until_exhausted_then_errors() {
+        let pool: Pool<4> = Pool::new(Layout::new::<u32>());
+        let layout = Layout::new::<u32>();
+
+        let mut allocations = Vec::new();
+        for _ in 0..4 {
+            allocations.push(pool.allocate(layout).expect("pool should have room"));
+        }
+
+        assert!(pool.allocate(layout).is_err());
+
+        for allocation in allocations {
+            let ptr = NonNull::new(allocation.as_ptr() as *mut u8).unwrap();
+            unsafe { pool.deallocate(ptr, layout) };
+        }
+    }
+
+    #[test]
+    fn slot_is_reusable_after_deallocate() {
+        let pool: Pool<1> = Pool::new(Layout::new::<u64>());
+        let layout = Layout::new::<u64>();
+
+        let first = pool.allocate(layout).unwrap();
+        assert!(pool.allocate(layout).is_err());
+
+        let ptr = NonNull::new(first.as_ptr() as *mut u8).unwrap();
+        unsafe { pool.deallocate(ptr, layout) };
+
+        assert!(pool.allocate(layout).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment")]
+    fn allocate_rejects_stricter_alignment_than_configured() {
+        // Slot is sized/aligned for two `u64`s (align 8); requesting a
+        // `u128` (align 16) of the same byte size must be rejected rather
+        // than handing back a misaligned block.
+        let pool: Pool<1> = Pool::new(Layout::new::<[u64; 2]>());
+        let _ = pool.allocate(Layout::new::<u128>());
+    }
+}